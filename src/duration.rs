@@ -6,7 +6,7 @@ use nom::{
     multi::many1,
     number::complete::double,
     sequence::{separated_pair, terminated},
-    Finish, IResult, Parser,
+    IResult, Parser,
 };
 use std::time::Duration;
 
@@ -115,14 +115,170 @@ fn number(input: &str) -> IResult<&str, f64> {
     double.map_opt(parse_decimal).parse(input)
 }
 
+// Parse a run of ISO 8601 components sharing an ordered set of designators.
+//
+// `designators` lists the legal `(letter, unit)` pairs in the order they may
+// appear (e.g. `Y`, `M`, `D` for the date part). Each component is a number
+// immediately followed by its designator; the designator must not appear
+// before an already consumed one, which is how `M` stays unambiguous between
+// months (date part) and minutes (time part). Only the last component may
+// carry a fractional value.
+//
+// `base` is the byte offset of `input` within the (trimmed) top-level string
+// so the reported positions point into the original input, like the phrase
+// parser's errors.
+fn iso8601_section(
+    input: &str,
+    base: usize,
+    designators: &[(char, Unit)],
+    acc: &mut Duration,
+    saw_fraction: &mut bool,
+) -> Result<(), Error> {
+    let mut rest = input;
+    let mut next = 0;
+
+    while !rest.is_empty() {
+        let pos = base + (input.len() - rest.len());
+        if *saw_fraction {
+            // Only the last component may be fractional; a further component
+            // here is not allowed.
+            return Err(Error::InvalidCharacter { pos });
+        }
+
+        let (after_number, value) = number(rest).map_err(|_| Error::NumberExpected { pos })?;
+
+        // The designator sits right after the number.
+        let designator_pos = base + (input.len() - after_number.len());
+        let designator = after_number
+            .chars()
+            .next()
+            .ok_or(Error::UnitExpected { pos: designator_pos })?;
+
+        let offset = designators[next..]
+            .iter()
+            .position(|(letter, _)| *letter == designator)
+            .ok_or_else(|| Error::UnknownUnit {
+                unit: designator.to_string(),
+                pos: designator_pos,
+            })?;
+        next += offset + 1;
+
+        *acc += convert_to_duration(value, designators[next - 1].1);
+        if value.fract() != 0.0 {
+            *saw_fraction = true;
+        }
+
+        rest = &after_number[designator.len_utf8()..];
+    }
+
+    Ok(())
+}
+
 // Parse a float followed by a unit
-fn time_span(input: &str) -> IResult<&str, Duration> {
+fn time_span(input: &str) -> IResult<&str, (f64, Unit)> {
     let number_input = separated_pair(number, opt(space0), unit);
     let and_with_spaces = recognize((opt(space1), tag("and"), opt(space1)));
     let duration_sep = alt((and_with_spaces, space1));
 
-    let (input, (value, unit)) = terminated(number_input, opt(duration_sep)).parse(input)?;
-    Ok((input, convert_to_duration(value, unit)))
+    terminated(number_input, opt(duration_sep)).parse(input)
+}
+
+// Classify why parsing stalled at `rest`, whose first byte sits at `pos` in
+// the (trimmed) input. The cases mirror the structure of `time_span`: a
+// number is expected first, then a time unit; anything else pinpoints the
+// exact failure so callers get an actionable, position-aware error.
+fn classify(rest: &str, pos: usize) -> Error {
+    let Ok((after, raw)) = double::<&str, nom::error::Error<&str>>(rest) else {
+        return Error::NumberExpected { pos };
+    };
+
+    if parse_decimal(raw).is_none() {
+        // `parse_decimal` rejects both negatives and values past `u64::MAX`;
+        // a negative here is a stray sign, not an oversized number.
+        return if raw < 0.0 {
+            Error::InvalidCharacter { pos }
+        } else {
+            Error::NumberOverflow { pos }
+        };
+    }
+
+    // A number parsed; the unit (possibly after spaces) is what went wrong.
+    let after = after.trim_start();
+    let unit_pos = pos + (rest.len() - after.len());
+    if after.is_empty() {
+        return Error::UnitExpected { pos: unit_pos };
+    }
+
+    let unknown: String = after.chars().take_while(|c| c.is_alphabetic()).collect();
+    if unknown.is_empty() {
+        Error::InvalidCharacter { pos: unit_pos }
+    } else {
+        Error::UnknownUnit {
+            unit: unknown,
+            pos: unit_pos,
+        }
+    }
+}
+
+/// A duration with its original per-unit quantities preserved.
+///
+/// Because a month is flattened as 30.44 days and a year as 365.25 days,
+/// collapsing straight to a [`Duration`] is lossy. Applications that apply a
+/// duration to a real calendar date need the field counts kept apart so they
+/// can do their own month-length-aware arithmetic, which is what this struct
+/// carries; call [`ParsedDuration::to_duration`] for the fixed-ratio
+/// flattening when the approximation is acceptable.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ParsedDuration {
+    pub years: f64,
+    pub months: f64,
+    pub weeks: f64,
+    pub days: f64,
+    pub hours: f64,
+    pub minutes: f64,
+    pub seconds: f64,
+    pub millis: f64,
+    pub micros: f64,
+    pub nanos: f64,
+}
+
+impl ParsedDuration {
+    // Accumulate a single `(value, unit)` span into its matching field.
+    fn add(&mut self, value: f64, unit: Unit) {
+        match unit {
+            Unit::Years => self.years += value,
+            Unit::Months => self.months += value,
+            Unit::Weeks => self.weeks += value,
+            Unit::Days => self.days += value,
+            Unit::Hours => self.hours += value,
+            Unit::Minutes => self.minutes += value,
+            Unit::Seconds => self.seconds += value,
+            Unit::Millis => self.millis += value,
+            Unit::Micros => self.micros += value,
+            Unit::Nanos => self.nanos += value,
+        }
+    }
+
+    /// Flatten into a [`Duration`] using the fixed unit ratios, treating a
+    /// month as 30.44 days and a year as 365.25 days.
+    pub fn to_duration(&self) -> Duration {
+        [
+            (self.years, Unit::Years),
+            (self.months, Unit::Months),
+            (self.weeks, Unit::Weeks),
+            (self.days, Unit::Days),
+            (self.hours, Unit::Hours),
+            (self.minutes, Unit::Minutes),
+            (self.seconds, Unit::Seconds),
+            (self.millis, Unit::Millis),
+            (self.micros, Unit::Micros),
+            (self.nanos, Unit::Nanos),
+        ]
+        .into_iter()
+        .fold(Duration::new(0, 0), |acc, (value, unit)| {
+            acc + convert_to_duration(value, unit)
+        })
+    }
 }
 
 /// Error parsing human-friendly duration
@@ -130,25 +286,68 @@ fn time_span(input: &str) -> IResult<&str, Duration> {
 pub enum Error {
     /// Input is empty.
     EmptyInput,
-    /// Failed to fully parse given input.
-    ParseFailed(String),
-    /// Error parsing input with nom.
-    Nom(nom::error::Error<String>),
+    /// A number was expected at the given byte offset.
+    NumberExpected { pos: usize },
+    /// A number was parsed but the time unit that must follow it is missing
+    /// at the given byte offset.
+    UnitExpected { pos: usize },
+    /// An unrecognised time unit was found at the given byte offset.
+    UnknownUnit { unit: String, pos: usize },
+    /// An unexpected character was found at the given byte offset.
+    InvalidCharacter { pos: usize },
+    /// A number at the given byte offset does not fit in a `u64`.
+    NumberOverflow { pos: usize },
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Error::EmptyInput => write!(f, "input is empty"),
-            Error::ParseFailed(left_over) => write!(f, "parsing duration failed at: {left_over}"),
-            Error::Nom(error) => write!(f, "parse duration error: {error}"),
+            Error::NumberExpected { pos } => write!(f, "expected number at {pos}"),
+            Error::UnitExpected { .. } => {
+                write!(f, "time unit needed, for example 123sec or 123ms")
+            }
+            Error::UnknownUnit { unit, .. } => write!(
+                f,
+                "unknown time unit {unit:?}, supported units: ns, us, ms, sec, \
+                 min, hours, days, weeks, months, years (and few variations)"
+            ),
+            Error::InvalidCharacter { pos } => write!(f, "invalid character at {pos}"),
+            Error::NumberOverflow { pos } => write!(f, "number is too large at {pos}"),
         }
     }
 }
 
-impl From<nom::error::Error<String>> for Error {
-    fn from(value: nom::error::Error<String>) -> Self {
-        Self::Nom(value)
+/// A [`Duration`] tagged with a sign
+///
+/// `std::time::Duration` cannot be negative, so relative-time expressions such
+/// as `-5min` are represented as a magnitude paired with a `negative` flag.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignedDuration {
+    /// Whether the duration points into the past.
+    pub negative: bool,
+    /// The magnitude of the duration.
+    pub duration: Duration,
+}
+
+impl SignedDuration {
+    /// Build a signed duration from a floating point number of seconds.
+    pub fn from_secs_f64(secs: f64) -> SignedDuration {
+        SignedDuration {
+            negative: secs.is_sign_negative(),
+            duration: Duration::from_secs_f64(secs.abs()),
+        }
+    }
+
+    /// Return the number of seconds this duration represents, negative when
+    /// the duration points into the past.
+    pub fn as_secs_f64(&self) -> f64 {
+        let secs = self.duration.as_secs_f64();
+        if self.negative {
+            -secs
+        } else {
+            secs
+        }
     }
 }
 
@@ -186,36 +385,277 @@ impl From<nom::error::Error<String>> for Error {
 /// assert_eq!(parse_duration("11e-1 days"), Ok(Duration::new(95_040, 0)));
 /// ```
 pub fn parse_duration(input: &str) -> Result<Duration, Error> {
+    let (duration, rest) = parse_duration_and_remainder(input)?;
+
+    let rest = rest.trim_start();
+    if !rest.is_empty() {
+        let trimmed = input.trim_start();
+        return Err(classify(rest, trimmed.len() - rest.len()));
+    }
+
+    Ok(duration)
+}
+
+/// Parse a leading duration and return the unconsumed tail `30min until …`
+///
+/// Runs the same span pipeline as [`parse_duration`] but, instead of failing
+/// when the input is not fully consumed, stops at the first token that is not
+/// part of the duration and hands back the remaining slice. This is useful
+/// for tokenizer-style callers such as command parsers and chat bots.
+/// [`parse_duration`] is this function plus a check that the remainder is
+/// empty.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use humantime::parse_duration_and_remainder;
+///
+/// assert_eq!(
+///     parse_duration_and_remainder("30min until the meeting"),
+///     Ok((Duration::new(1800, 0), "until the meeting"))
+/// );
+/// ```
+pub fn parse_duration_and_remainder(input: &str) -> Result<(Duration, &str), Error> {
+    let input = input.trim_start();
+    if input.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+
+    if input.starts_with('-') {
+        return Err(Error::InvalidCharacter { pos: 0 });
+    }
+
+    if input.trim_end() == "0" {
+        return Ok((Duration::new(0, 0), ""));
+    }
+
+    let (parsed, rest) = accumulate_spans(input)?;
+    Ok((parsed.to_duration(), rest))
+}
+
+// Run `many1(time_span)` over `input` and fold the spans into a
+// `ParsedDuration`, returning it alongside the unconsumed tail. `input` must
+// already be trimmed at the front and known to be a non-empty, non-negative,
+// non-`"0"` string.
+fn accumulate_spans(input: &str) -> Result<(ParsedDuration, &str), Error> {
+    let (rest, spans) = match many1(time_span).parse(input) {
+        Ok(parsed) => parsed,
+        // `many1` only fails when not even the first span parses, so the
+        // failure is right at the start of the input.
+        Err(_) => return Err(classify(input, 0)),
+    };
+
+    let mut parsed = ParsedDuration::default();
+    for (value, unit) in spans {
+        parsed.add(value, unit);
+    }
+    Ok((parsed, rest))
+}
+
+/// Parse a duration while preserving its per-unit field counts
+///
+/// Behaves exactly like [`parse_duration`] but returns a [`ParsedDuration`]
+/// that keeps years, months and days distinct instead of flattening them to
+/// seconds. [`parse_duration`] folds the spans into the same
+/// [`ParsedDuration`] and flattens it with [`ParsedDuration::to_duration`].
+///
+/// # Examples
+///
+/// ```
+/// use humantime::parse_components;
+///
+/// let parsed = parse_components("1year 6months").unwrap();
+/// assert_eq!(parsed.years, 1.0);
+/// assert_eq!(parsed.months, 6.0);
+/// ```
+pub fn parse_components(input: &str) -> Result<ParsedDuration, Error> {
     let input = input.trim();
     if input.is_empty() {
         return Err(Error::EmptyInput);
     }
 
+    // Unsigned durations cannot be negative; a leading sign is only accepted
+    // by `parse_signed_duration`.
+    if input.starts_with('-') {
+        return Err(Error::InvalidCharacter { pos: 0 });
+    }
+
     if input == "0" {
-        return Ok(Duration::new(0, 0));
+        return Ok(ParsedDuration::default());
     }
 
-    let (input, durations) = many1(time_span)
-        .parse(input)
-        .map_err(|e| e.to_owned())
-        .finish()?;
+    let (parsed, rest) = accumulate_spans(input)?;
+
+    let rest = rest.trim_start();
+    if !rest.is_empty() {
+        return Err(classify(rest, input.len() - rest.len()));
+    }
 
+    Ok(parsed)
+}
+
+/// Parse a duration with an optional leading sign `-5min`
+///
+/// Accepts an optional `+` or `-` before the span sequence and returns a
+/// [`SignedDuration`]. The magnitude is parsed exactly like
+/// [`parse_duration`]; only the sign prefix is handled here. Unlike this
+/// function, [`parse_duration`] rejects a leading `-`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use humantime::{parse_signed_duration, SignedDuration};
+///
+/// assert_eq!(
+///     parse_signed_duration("-5min"),
+///     Ok(SignedDuration { negative: true, duration: Duration::new(300, 0) })
+/// );
+/// assert_eq!(
+///     parse_signed_duration("1h 30min"),
+///     Ok(SignedDuration { negative: false, duration: Duration::new(5400, 0) })
+/// );
+/// ```
+pub fn parse_signed_duration(input: &str) -> Result<SignedDuration, Error> {
     let input = input.trim();
-    if !input.trim().is_empty() {
-        return Err(Error::ParseFailed(input.to_owned()));
+    if input.is_empty() {
+        return Err(Error::EmptyInput);
     }
 
-    let total_duration = durations
-        .into_iter()
-        .fold(Duration::new(0, 0), |acc, duration| acc + duration);
-    Ok(total_duration)
+    let (negative, magnitude) = match input.strip_prefix('-') {
+        Some(magnitude) => (true, magnitude),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+
+    // A bare sign has no magnitude to parse.
+    if magnitude.trim().is_empty() {
+        return Err(Error::NumberExpected {
+            pos: input.len() - magnitude.len(),
+        });
+    }
+
+    let shift = input.len() - magnitude.trim_start().len();
+    let duration = parse_components(magnitude)
+        .map_err(|err| match err {
+            Error::NumberExpected { pos } => Error::NumberExpected { pos: pos + shift },
+            Error::UnitExpected { pos } => Error::UnitExpected { pos: pos + shift },
+            Error::UnknownUnit { unit, pos } => Error::UnknownUnit {
+                unit,
+                pos: pos + shift,
+            },
+            Error::InvalidCharacter { pos } => Error::InvalidCharacter { pos: pos + shift },
+            Error::NumberOverflow { pos } => Error::NumberOverflow { pos: pos + shift },
+            Error::EmptyInput => Error::EmptyInput,
+        })?
+        .to_duration();
+    Ok(SignedDuration { negative, duration })
+}
+
+/// Parse an ISO 8601 duration `P3Y6M4DT12H30M5S`
+///
+/// The grammar is an optional sign, a mandatory `P`, an optional date part
+/// and an optional time part introduced by `T`. Date components use the
+/// designators `Y`, `M`, `D` and time components use `H`, `M`, `S`, each in
+/// that order; `M` therefore means months before the `T` and minutes after
+/// it. The pure-week form `PnW` is accepted on its own and cannot be combined
+/// with other fields. At least one component must follow `P`, and only the
+/// last (smallest) present component may carry a fractional value. Years and
+/// months use the same fixed ratios as [`parse_duration`].
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use humantime::parse_iso8601_duration;
+///
+/// assert_eq!(parse_iso8601_duration("PT0.5S"), Ok(Duration::new(0, 500_000_000)));
+/// assert_eq!(parse_iso8601_duration("P1W"), Ok(Duration::new(604_800, 0)));
+/// assert_eq!(parse_iso8601_duration("PT12H30M5S"), Ok(Duration::new(45_005, 0)));
+/// ```
+pub fn parse_iso8601_duration(input: &str) -> Result<Duration, Error> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(Error::EmptyInput);
+    }
+
+    // A leading `+` is accepted for symmetry with the grammar; a leading `-`
+    // denotes a negative duration, which `std::time::Duration` cannot hold.
+    let (body, mut pos) = match input.strip_prefix('+') {
+        Some(body) => (body, 1),
+        None => (input, 0),
+    };
+    if body.starts_with('-') {
+        return Err(Error::InvalidCharacter { pos });
+    }
+    let body = body
+        .strip_prefix('P')
+        .ok_or(Error::InvalidCharacter { pos })?;
+    pos += 1;
+    if body.is_empty() {
+        return Err(Error::NumberExpected { pos });
+    }
+
+    let (date_part, time_part) = match body.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (body, None),
+    };
+
+    let mut total = Duration::new(0, 0);
+    let mut saw_fraction = false;
+
+    if date_part.contains('W') {
+        // The week form is exclusive: `P1W` only, never mixed with other
+        // designators or a time part.
+        if time_part.is_some() {
+            // Point at the `T` that should not be here.
+            return Err(Error::InvalidCharacter {
+                pos: pos + date_part.len(),
+            });
+        }
+        iso8601_section(
+            date_part,
+            pos,
+            &[('W', Unit::Weeks)],
+            &mut total,
+            &mut saw_fraction,
+        )?;
+    } else if !date_part.is_empty() {
+        iso8601_section(
+            date_part,
+            pos,
+            &[('Y', Unit::Years), ('M', Unit::Months), ('D', Unit::Days)],
+            &mut total,
+            &mut saw_fraction,
+        )?;
+    }
+
+    if let Some(time_part) = time_part {
+        // The time part starts after the date part and the `T` separator.
+        let time_pos = pos + date_part.len() + 1;
+        if time_part.is_empty() {
+            return Err(Error::NumberExpected { pos: time_pos });
+        }
+        iso8601_section(
+            time_part,
+            time_pos,
+            &[('H', Unit::Hours), ('M', Unit::Minutes), ('S', Unit::Seconds)],
+            &mut total,
+            &mut saw_fraction,
+        )?;
+    }
+
+    Ok(total)
 }
 
 #[cfg(test)]
 mod test {
     use crate::format_duration;
 
-    use super::{parse_duration, Error};
+    use super::{
+        parse_components, parse_duration, parse_duration_and_remainder, parse_iso8601_duration,
+        parse_signed_duration, Error, SignedDuration,
+    };
     use std::time::Duration;
 
     macro_rules! assert_parse_duration_ok {
@@ -228,10 +668,7 @@ mod test {
         ($input:expr) => {
             assert_eq!(
                 parse_duration($input),
-                Err(Error::Nom(nom::error::Error::new(
-                    $input.to_owned(),
-                    nom::error::ErrorKind::MapOpt
-                )))
+                Err(Error::NumberOverflow { pos: 0 })
             );
         };
     }
@@ -435,6 +872,167 @@ mod test {
         assert_parse_duration_ok!("2hand 15m", 8100, 0);
     }
 
+    #[test]
+    fn test_iso8601() {
+        assert_eq!(
+            parse_iso8601_duration("PT12H30M5S"),
+            Ok(Duration::new(45_005, 0))
+        );
+        assert_eq!(
+            parse_iso8601_duration("P1W"),
+            Ok(Duration::new(604_800, 0))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT0.5S"),
+            Ok(Duration::new(0, 500_000_000))
+        );
+        assert_eq!(
+            parse_iso8601_duration("P1Y"),
+            Ok(Duration::new(31_557_600, 0))
+        );
+        assert_eq!(
+            parse_iso8601_duration("P1M"),
+            Ok(Duration::new(2_630_016, 0))
+        );
+        assert_eq!(
+            parse_iso8601_duration("PT1M"),
+            Ok(Duration::new(60, 0))
+        );
+        assert_eq!(
+            parse_iso8601_duration("+PT30S"),
+            Ok(Duration::new(30, 0))
+        );
+    }
+
+    #[test]
+    fn test_iso8601_errors() {
+        assert_eq!(parse_iso8601_duration(""), Err(Error::EmptyInput));
+        assert!(parse_iso8601_duration("P").is_err());
+        assert!(parse_iso8601_duration("PT").is_err());
+        assert!(parse_iso8601_duration("3Y").is_err());
+        // Week form cannot be combined with other components.
+        assert!(parse_iso8601_duration("P1W2D").is_err());
+        assert!(parse_iso8601_duration("P1WT1H").is_err());
+        // Designators must be in order.
+        assert!(parse_iso8601_duration("P1D1Y").is_err());
+        // Negative durations cannot be represented.
+        assert!(parse_iso8601_duration("-PT30S").is_err());
+        // Only the smallest component may be fractional.
+        assert!(parse_iso8601_duration("PT0.5H30M").is_err());
+    }
+
+    #[test]
+    fn test_components() {
+        let parsed = parse_components("1year 6months 10days").unwrap();
+        assert_eq!(parsed.years, 1.0);
+        assert_eq!(parsed.months, 6.0);
+        assert_eq!(parsed.days, 10.0);
+        assert_eq!(parsed.hours, 0.0);
+
+        // Repeated units accumulate rather than overwrite.
+        let parsed = parse_components("2h 15m 15m").unwrap();
+        assert_eq!(parsed.hours, 2.0);
+        assert_eq!(parsed.minutes, 30.0);
+
+        // Flattening matches the plain `parse_duration` result.
+        assert_eq!(
+            parse_components("2h 37min").unwrap().to_duration(),
+            parse_duration("2h 37min").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!(
+            parse_duration("123").unwrap_err().to_string(),
+            "time unit needed, for example 123sec or 123ms"
+        );
+        assert_eq!(
+            parse_duration("10nights").unwrap_err().to_string(),
+            "unknown time unit \"nights\", supported units: ns, us, ms, sec, \
+             min, hours, days, weeks, months, years (and few variations)"
+        );
+        assert_eq!(
+            parse_duration("\0").unwrap_err().to_string(),
+            "expected number at 0"
+        );
+        assert_eq!(
+            parse_duration("1~").unwrap_err().to_string(),
+            "invalid character at 1"
+        );
+    }
+
+    #[test]
+    fn test_error_variants() {
+        assert_eq!(parse_duration("123"), Err(Error::UnitExpected { pos: 3 }));
+        assert_eq!(
+            parse_duration("10nights"),
+            Err(Error::UnknownUnit {
+                unit: "nights".to_owned(),
+                pos: 2
+            })
+        );
+        assert_eq!(parse_duration("\0"), Err(Error::NumberExpected { pos: 0 }));
+        assert_eq!(parse_duration("1~"), Err(Error::InvalidCharacter { pos: 1 }));
+        assert_eq!(parse_duration(""), Err(Error::EmptyInput));
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(
+            parse_signed_duration("-5min"),
+            Ok(SignedDuration {
+                negative: true,
+                duration: Duration::new(300, 0)
+            })
+        );
+        assert_eq!(
+            parse_signed_duration("+5min"),
+            Ok(SignedDuration {
+                negative: false,
+                duration: Duration::new(300, 0)
+            })
+        );
+        assert_eq!(
+            parse_signed_duration("1h 30min"),
+            Ok(SignedDuration {
+                negative: false,
+                duration: Duration::new(5400, 0)
+            })
+        );
+
+        // `parse_duration` still refuses a leading minus.
+        assert_eq!(
+            parse_duration("-5min"),
+            Err(Error::InvalidCharacter { pos: 0 })
+        );
+
+        assert_eq!(SignedDuration::from_secs_f64(-1.5).as_secs_f64(), -1.5);
+        assert_eq!(SignedDuration::from_secs_f64(2.0).as_secs_f64(), 2.0);
+    }
+
+    #[test]
+    fn test_remainder() {
+        assert_eq!(
+            parse_duration_and_remainder("30min until the meeting"),
+            Ok((Duration::new(1800, 0), "until the meeting"))
+        );
+        assert_eq!(
+            parse_duration_and_remainder("2h 15m left"),
+            Ok((Duration::new(8100, 0), "left"))
+        );
+        // A fully consumed input leaves an empty remainder.
+        assert_eq!(
+            parse_duration_and_remainder("5s"),
+            Ok((Duration::new(5, 0), ""))
+        );
+        // `parse_duration` still rejects the leftover tail.
+        assert_eq!(
+            parse_duration("30min until the meeting"),
+            Err(Error::NumberExpected { pos: 6 })
+        );
+    }
+
     #[test]
     fn test_overlow() {
         assert_parse_duration_err!("100000000000000000000ns");